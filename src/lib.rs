@@ -3,27 +3,155 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::{fs::read_to_string, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fs::{read_to_string, write},
+    path::Path,
+};
+use thiserror::Error;
 
 /// A type representing the possible object-space configurations.
 ///
-/// Configurations comprise a detector-descriptor pairing for each component type within the
-/// system. This means that cameras will have a distinct detector / descriptor pairing from e.g.
-/// LiDAR components.
-///
-/// At the present time, only cameras are currently supported.
-#[derive(Debug, Serialize, Deserialize)]
+/// Configurations comprise a detector-descriptor pairing for each component within the system,
+/// keyed by a user-chosen component name (e.g. `cam_left`, `cam_right`, `lidar_top`). This means
+/// that cameras will have a distinct detector / descriptor pairing from e.g. LiDAR components,
+/// and a rig may describe as many components of each kind as it has sensors.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct ObjectSpaceConfig {
-    /// Configuration for camera components.
-    camera: DetectorDescriptor,
+    /// The rig's components, keyed by user-chosen component name.
+    components: HashMap<String, Component>,
+}
+
+impl ObjectSpaceConfig {
+    /// Validates that this config is semantically sound, beyond what structural TOML
+    /// deserialization already guarantees.
+    ///
+    /// This checks board geometry (e.g. `width`/`height` and marker/edge lengths), that the
+    /// detector and descriptor in each component are a valid pairing, and that at least one
+    /// component is present.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.components.is_empty() {
+            return Err(ConfigError::NoComponents);
+        }
+
+        for (name, component) in &self.components {
+            component.detector_descriptor.validate(name)?;
+        }
+
+        Ok(())
+    }
+
+    /// Iterates over all components, yielding each component's name alongside it.
+    pub fn components(&self) -> impl Iterator<Item = (&str, &Component)> {
+        self.components
+            .iter()
+            .map(|(name, component)| (name.as_str(), component))
+    }
+
+    /// Looks up a component by its name.
+    pub fn component(&self, name: &str) -> Option<&Component> {
+        self.components.get(name)
+    }
+
+    /// Iterates over the components of the given kind, yielding each component's name alongside
+    /// it.
+    pub fn components_of_kind(
+        &self,
+        kind: ComponentKind,
+    ) -> impl Iterator<Item = (&str, &Component)> {
+        self.components()
+            .filter(move |(_, component)| component.kind == kind)
+    }
+}
+
+/// A single named component of a rig, e.g. a camera or a LiDAR.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct Component {
+    /// The kind of component this is.
+    pub kind: ComponentKind,
+
+    /// The detector-descriptor pairing for observations from this component.
+    #[serde(flatten)]
+    pub detector_descriptor: DetectorDescriptor,
+}
+
+/// The kinds of component a rig can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentKind {
+    /// A camera component.
+    Camera,
+    /// A LiDAR component.
+    Lidar,
+}
+
+/// Errors produced by [`ObjectSpaceConfig::validate`].
+#[derive(Debug, Error, PartialEq)]
+pub enum ConfigError {
+    /// The config declared no components at all.
+    #[error("config must declare at least one component")]
+    NoComponents,
+
+    /// A board's `width` or `height` was less than the minimum of 2 checker squares / markers.
+    #[error("{component} {field} must be >= 2, got {value}")]
+    BoardDimensionTooSmall {
+        component: String,
+        field: &'static str,
+        value: i64,
+    },
+
+    /// A board's `edge_length` was not strictly positive.
+    #[error("{component} edge_length must be > 0, got {value}")]
+    NonPositiveEdgeLength { component: String, value: f64 },
+
+    /// A board's `marker_length` was not strictly positive.
+    #[error("{component} marker_length must be > 0, got {value}")]
+    NonPositiveMarkerLength { component: String, value: f64 },
+
+    /// A `Charuco` board's `marker_length` was not strictly less than its `edge_length`.
+    #[error(
+        "{component} marker_length ({marker_length}) must be less than edge_length ({edge_length})"
+    )]
+    MarkerLengthNotLessThanEdgeLength {
+        component: String,
+        marker_length: f64,
+        edge_length: f64,
+    },
+
+    /// A detector's `variances` entry had a negative diagonal variance.
+    #[error("{component} variances has a negative diagonal entry: {value}")]
+    NegativeVariance { component: String, value: f64 },
+
+    /// The descriptor paired with a detector is not in that detector's allowed set.
+    #[error("{component} descriptor \"{descriptor}\" is not valid for detector \"{detector}\"")]
+    DescriptorNotAllowedForDetector {
+        component: String,
+        detector: &'static str,
+        descriptor: &'static str,
+    },
+
+    /// A landmark's covariance had a negative diagonal entry.
+    #[error("{component} landmark {landmark_id} covariance has a negative diagonal entry: {value}")]
+    NegativeLandmarkCovariance {
+        component: String,
+        landmark_id: u64,
+        value: f64,
+    },
+
+    /// A landmark map contained two landmarks sharing the same ID.
+    #[error("{component} has more than one landmark with id {landmark_id}")]
+    DuplicateLandmarkId { component: String, landmark_id: u64 },
 }
 
-/// A type representing the detector-descriptor pairing for a camera.
+/// A type representing the detector-descriptor pairing for a single component.
 ///
 /// Not every variant of detector and descriptor is guaranteed to be semantically valid when paired
 /// together.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 pub struct DetectorDescriptor {
@@ -35,9 +163,26 @@ pub struct DetectorDescriptor {
     descriptor: Descriptor,
 }
 
+impl DetectorDescriptor {
+    fn validate(&self, component: &str) -> Result<(), ConfigError> {
+        self.detector.validate(component)?;
+        self.descriptor.validate(component)?;
+
+        if !self.detector.allows(&self.descriptor) {
+            return Err(ConfigError::DescriptorNotAllowedForDetector {
+                component: component.to_string(),
+                detector: self.detector.kind_name(),
+                descriptor: self.descriptor.kind_name(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 /// A type describing the possible detectors that can be used on component observations, and their
 /// parameters.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
@@ -54,8 +199,9 @@ pub enum Detector {
         height: i64,
         /// Size of one edge of a checker square, in metres.
         edge_length: f64,
-        /// The variances (X/Y/Z) of object-space points, in metres^2.
-        variances: Vec<f64>,
+        /// The uncertainty of object-space points: 3 diagonal variances (X/Y/Z), 6
+        /// upper-triangular covariance terms, or a full 9-value covariance matrix, in metres^2.
+        variances: Uncertainty,
     },
 
     /// Detector for a ChArUco board within a camera image.
@@ -74,33 +220,662 @@ pub enum Detector {
         ///
         /// Should be smaller than `edge_length`.
         marker_length: f64,
-        /// The variances (X/Y/Z) of object-space points, in metres^2.
-        variances: Vec<f64>,
+        /// The uncertainty of object-space points: 3 diagonal variances (X/Y/Z), 6
+        /// upper-triangular covariance terms, or a full 9-value covariance matrix, in metres^2.
+        variances: Uncertainty,
     },
+
+    /// Detector for a planar checkerboard target within a LiDAR point cloud.
+    ///
+    /// The board plane is recovered from the point cloud (e.g. via RANSAC plane-fitting) rather
+    /// than from image features, so the detector needs a tolerance for how far a point may lie
+    /// from the fitted plane and still be considered part of the board.
+    ///
+    /// Valid descriptors are:
+    ///
+    /// - `"detector_defined"`
+    PlanarTarget {
+        /// Number of checker squares horizontally on the board.
+        width: i64,
+        /// Number of checker squares vertically on the board.
+        height: i64,
+        /// Size of one edge of a checker square, in metres.
+        edge_length: f64,
+        /// Maximum allowed distance, in metres, between a point and the fitted board plane for
+        /// that point to be accepted as belonging to the board during RANSAC/plane-fitting.
+        plane_fit_tolerance: f64,
+        /// The uncertainty of object-space points: 3 diagonal variances (X/Y/Z), 6
+        /// upper-triangular covariance terms, or a full 9-value covariance matrix, in metres^2.
+        variances: Uncertainty,
+    },
+
+    /// Detector for a grid of ArUco/AprilTag markers within a camera image.
+    ///
+    /// Unlike `Charuco`, a bare marker grid has no checker squares between the markers, so the
+    /// board geometry is fully specified by the marker count, marker edge length, and the
+    /// separation between adjacent markers.
+    ///
+    /// Valid descriptors are:
+    ///
+    /// - `"detector_defined"`
+    /// - `"landmark_map"`, since individual markers are already uniquely identified and can be
+    ///   tied to surveyed landmark IDs
+    ArucoGrid {
+        /// The marker dictionary the board's markers are drawn from.
+        dictionary: ArucoDictionary,
+        /// Number of markers horizontally on the board.
+        markers_x: i64,
+        /// Number of markers vertically on the board.
+        markers_y: i64,
+        /// Size of one edge of a marker, in metres.
+        marker_length: f64,
+        /// Separation between adjacent markers, in metres.
+        marker_separation: f64,
+        /// The uncertainty of object-space points: 3 diagonal variances (X/Y/Z), 6
+        /// upper-triangular covariance terms, or a full 9-value covariance matrix, in metres^2.
+        variances: Uncertainty,
+    },
+}
+
+impl Detector {
+    fn validate(&self, component: &str) -> Result<(), ConfigError> {
+        match self {
+            Detector::Checkerboard {
+                width,
+                height,
+                edge_length,
+                variances,
+            } => {
+                validate_board_dimensions(component, "width", *width, "height", *height)?;
+                validate_edge_length(component, *edge_length)?;
+                validate_variances(component, variances)?;
+            }
+            Detector::Charuco {
+                width,
+                height,
+                edge_length,
+                marker_length,
+                variances,
+            } => {
+                validate_board_dimensions(component, "width", *width, "height", *height)?;
+                validate_edge_length(component, *edge_length)?;
+                validate_marker_length(component, *marker_length)?;
+                if *marker_length >= *edge_length {
+                    return Err(ConfigError::MarkerLengthNotLessThanEdgeLength {
+                        component: component.to_string(),
+                        marker_length: *marker_length,
+                        edge_length: *edge_length,
+                    });
+                }
+                validate_variances(component, variances)?;
+            }
+            Detector::PlanarTarget {
+                width,
+                height,
+                edge_length,
+                variances,
+                ..
+            } => {
+                validate_board_dimensions(component, "width", *width, "height", *height)?;
+                validate_edge_length(component, *edge_length)?;
+                validate_variances(component, variances)?;
+            }
+            Detector::ArucoGrid {
+                markers_x,
+                markers_y,
+                marker_length,
+                variances,
+                ..
+            } => {
+                validate_board_dimensions(component, "markers_x", *markers_x, "markers_y", *markers_y)?;
+                validate_marker_length(component, *marker_length)?;
+                validate_variances(component, variances)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `descriptor` is a semantically valid pairing for this detector.
+    fn allows(&self, descriptor: &Descriptor) -> bool {
+        matches!(
+            (self, descriptor),
+            (_, Descriptor::DetectorDefined)
+                | (Detector::ArucoGrid { .. }, Descriptor::LandmarkMap { .. })
+        )
+    }
+
+    /// The name of this detector's variant, for use in error messages.
+    fn kind_name(&self) -> &'static str {
+        match self {
+            Detector::Checkerboard { .. } => "checkerboard",
+            Detector::Charuco { .. } => "charuco",
+            Detector::PlanarTarget { .. } => "planar_target",
+            Detector::ArucoGrid { .. } => "aruco_grid",
+        }
+    }
+}
+
+fn validate_board_dimensions(
+    component: &str,
+    field_x: &'static str,
+    x: i64,
+    field_y: &'static str,
+    y: i64,
+) -> Result<(), ConfigError> {
+    if x < 2 {
+        return Err(ConfigError::BoardDimensionTooSmall {
+            component: component.to_string(),
+            field: field_x,
+            value: x,
+        });
+    }
+    if y < 2 {
+        return Err(ConfigError::BoardDimensionTooSmall {
+            component: component.to_string(),
+            field: field_y,
+            value: y,
+        });
+    }
+    Ok(())
+}
+
+fn validate_edge_length(component: &str, edge_length: f64) -> Result<(), ConfigError> {
+    if edge_length <= 0.0 {
+        return Err(ConfigError::NonPositiveEdgeLength {
+            component: component.to_string(),
+            value: edge_length,
+        });
+    }
+    Ok(())
+}
+
+fn validate_marker_length(component: &str, marker_length: f64) -> Result<(), ConfigError> {
+    if marker_length <= 0.0 {
+        return Err(ConfigError::NonPositiveMarkerLength {
+            component: component.to_string(),
+            value: marker_length,
+        });
+    }
+    Ok(())
+}
+
+fn validate_variances(component: &str, variances: &Uncertainty) -> Result<(), ConfigError> {
+    let matrix = variances.matrix();
+    for diagonal in [matrix[0][0], matrix[1][1], matrix[2][2]] {
+        if diagonal < 0.0 {
+            return Err(ConfigError::NegativeVariance {
+                component: component.to_string(),
+                value: diagonal,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The named marker dictionaries that an `ArucoGrid` detector can decode.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArucoDictionary {
+    /// The original ArUco dictionary.
+    ArucoOriginal,
+    /// The 4x4, 50-marker dictionary.
+    #[serde(rename = "4x4_50")]
+    Aruco4x4_50,
+    /// The 5x5, 100-marker dictionary.
+    #[serde(rename = "5x5_100")]
+    Aruco5x5_100,
+    /// The 6x6, 250-marker dictionary.
+    #[serde(rename = "6x6_250")]
+    Aruco6x6_250,
+    /// The AprilTag 36h11 dictionary.
+    Apriltag36h11,
 }
 
 /// A type describing the possible descriptors for the object-space detected in an image.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 pub enum Descriptor {
     /// The descriptor is to be defined in terms of the detector and its parameters.
     DetectorDefined,
+
+    /// The descriptor is an explicit map of surveyed object-space landmarks, each identified by
+    /// a unique ID and a 3D location.
+    ///
+    /// This supports calibration against independently surveyed fiducials or a total-station
+    /// measured target field, where the detector's output is tied to known world coordinates by
+    /// landmark ID rather than derived from a regular board's geometry.
+    LandmarkMap {
+        /// The surveyed landmarks making up the map.
+        landmarks: Vec<Landmark>,
+    },
+}
+
+impl Descriptor {
+    fn validate(&self, component: &str) -> Result<(), ConfigError> {
+        match self {
+            Descriptor::DetectorDefined => Ok(()),
+            Descriptor::LandmarkMap { landmarks } => {
+                let mut seen_ids = HashSet::new();
+
+                for landmark in landmarks {
+                    if !seen_ids.insert(landmark.id) {
+                        return Err(ConfigError::DuplicateLandmarkId {
+                            component: component.to_string(),
+                            landmark_id: landmark.id,
+                        });
+                    }
+
+                    if let Some(covariance) = landmark.covariance {
+                        let matrix = covariance.matrix();
+                        for diagonal in [matrix[0][0], matrix[1][1], matrix[2][2]] {
+                            if diagonal < 0.0 {
+                                return Err(ConfigError::NegativeLandmarkCovariance {
+                                    component: component.to_string(),
+                                    landmark_id: landmark.id,
+                                    value: diagonal,
+                                });
+                            }
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// The name of this descriptor's variant, for use in error messages.
+    fn kind_name(&self) -> &'static str {
+        match self {
+            Descriptor::DetectorDefined => "detector_defined",
+            Descriptor::LandmarkMap { .. } => "landmark_map",
+        }
+    }
+}
+
+/// A single surveyed object-space point within a `Descriptor::LandmarkMap`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct Landmark {
+    /// The unique ID of this landmark, used to tie detector output to this point.
+    pub id: u64,
+    /// X coordinate of the landmark, in metres.
+    pub x: f64,
+    /// Y coordinate of the landmark, in metres.
+    pub y: f64,
+    /// Z coordinate of the landmark, in metres.
+    pub z: f64,
+    /// The optional uncertainty of this landmark's surveyed position, in metres^2.
+    pub covariance: Option<Uncertainty>,
+}
+
+/// An object-space uncertainty, stored internally as a symmetric 3x3 covariance matrix.
+///
+/// Parses from either 3 values (diagonal X/Y/Z variances), 6 values (the upper-triangular part
+/// of a symmetric 3x3 in row-major order: σxx, σxy, σxz, σyy, σyz, σzz), or 9 values (the full
+/// row-major 3x3). The reconstructed matrix must be symmetric (for the 9-value case, within a
+/// small tolerance) and positive semi-definite, or parsing fails.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(into = "Vec<f64>", try_from = "Vec<f64>")]
+pub struct Uncertainty {
+    matrix: [[f64; 3]; 3],
+}
+
+impl Uncertainty {
+    /// The symmetric 3x3 covariance matrix, in row-major order.
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+}
+
+impl TryFrom<Vec<f64>> for Uncertainty {
+    type Error = String;
+
+    fn try_from(values: Vec<f64>) -> Result<Self, Self::Error> {
+        let matrix = match values.as_slice() {
+            [vx, vy, vz] => [[*vx, 0.0, 0.0], [0.0, *vy, 0.0], [0.0, 0.0, *vz]],
+            [xx, xy, xz, yy, yz, zz] => [[*xx, *xy, *xz], [*xy, *yy, *yz], [*xz, *yz, *zz]],
+            [a, b, c, d, e, f, g, h, i] => [[*a, *b, *c], [*d, *e, *f], [*g, *h, *i]],
+            _ => {
+                return Err(format!(
+                    "uncertainty must have 3, 6, or 9 values, got {}",
+                    values.len()
+                ))
+            }
+        };
+
+        if values.len() == 9 {
+            const SYMMETRY_TOLERANCE: f64 = 1e-9;
+            let off_diagonal_pairs = [(0, 1), (0, 2), (1, 2)];
+            if let Some(&(i, j)) = off_diagonal_pairs
+                .iter()
+                .find(|&&(i, j)| (matrix[i][j] - matrix[j][i]).abs() > SYMMETRY_TOLERANCE)
+            {
+                return Err(format!(
+                    "uncertainty matrix is not symmetric: entry [{i}][{j}] = {} but [{j}][{i}] = {}",
+                    matrix[i][j], matrix[j][i]
+                ));
+            }
+        }
+
+        if !is_positive_semidefinite(&matrix) {
+            return Err(format!("uncertainty matrix {matrix:?} is not positive semi-definite"));
+        }
+
+        Ok(Uncertainty { matrix })
+    }
+}
+
+impl From<Uncertainty> for Vec<f64> {
+    fn from(uncertainty: Uncertainty) -> Self {
+        uncertainty.matrix.into_iter().flatten().collect()
+    }
+}
+
+/// Checks whether a symmetric 3x3 matrix is positive semi-definite.
+///
+/// Unlike positive *definite*ness, this cannot be tested by checking only the leading principal
+/// minors (that test can miss a negative eigenvalue hidden behind an earlier zero, e.g.
+/// `diag(0, -1, 0)`). Instead, this checks that *all* principal minors — every diagonal entry,
+/// every 2x2 principal submatrix determinant, and the full determinant — are nonnegative (within
+/// a small tolerance), which is both necessary and sufficient for a symmetric matrix.
+fn is_positive_semidefinite(matrix: &[[f64; 3]; 3]) -> bool {
+    const EPSILON: f64 = 1e-9;
+
+    let diagonal_minors = [matrix[0][0], matrix[1][1], matrix[2][2]];
+
+    let two_by_two_minors = [
+        matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[1][0],
+        matrix[0][0] * matrix[2][2] - matrix[0][2] * matrix[2][0],
+        matrix[1][1] * matrix[2][2] - matrix[1][2] * matrix[2][1],
+    ];
+
+    let determinant = matrix[0][0] * (matrix[1][1] * matrix[2][2] - matrix[1][2] * matrix[2][1])
+        - matrix[0][1] * (matrix[1][0] * matrix[2][2] - matrix[1][2] * matrix[2][0])
+        + matrix[0][2] * (matrix[1][0] * matrix[2][1] - matrix[1][1] * matrix[2][0]);
+
+    diagonal_minors
+        .into_iter()
+        .chain(two_by_two_minors)
+        .chain([determinant])
+        .all(|principal_minor| principal_minor >= -EPSILON)
 }
 
 /// A function to read in the object space config from a TOML file at the given path.
+///
+/// The config is validated (see [`ObjectSpaceConfig::validate`]) before being returned.
 pub fn read_object_space_config<P>(toml_path: P) -> Result<ObjectSpaceConfig>
 where
     P: AsRef<Path>,
 {
-    Ok(toml::from_str(&read_to_string(toml_path)?)?)
+    let config: ObjectSpaceConfig = toml::from_str(&read_to_string(toml_path)?)?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// A function to serialize an object space config to a TOML string.
+pub fn to_toml_string(config: &ObjectSpaceConfig) -> Result<String> {
+    Ok(toml::to_string(config)?)
+}
+
+/// A function to write an object space config out to a TOML file at the given path.
+pub fn write_object_space_config<P>(config: &ObjectSpaceConfig, toml_path: P) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    Ok(write(toml_path, to_toml_string(config)?)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn checkerboard(width: i64, height: i64, edge_length: f64) -> Detector {
+        Detector::Checkerboard {
+            width,
+            height,
+            edge_length,
+            variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn config_with_no_components_is_rejected() {
+        let config = ObjectSpaceConfig {
+            components: HashMap::new(),
+        };
+        assert_eq!(config.validate().unwrap_err(), ConfigError::NoComponents);
+    }
+
+    #[test]
+    fn components_can_be_looked_up_by_name_and_filtered_by_kind() {
+        let mut components = HashMap::new();
+        components.insert(
+            "cam_left".to_string(),
+            Component {
+                kind: ComponentKind::Camera,
+                detector_descriptor: DetectorDescriptor {
+                    detector: checkerboard(7, 5, 0.1),
+                    descriptor: Descriptor::DetectorDefined,
+                },
+            },
+        );
+        components.insert(
+            "lidar_top".to_string(),
+            Component {
+                kind: ComponentKind::Lidar,
+                detector_descriptor: DetectorDescriptor {
+                    detector: Detector::PlanarTarget {
+                        width: 7,
+                        height: 5,
+                        edge_length: 0.1,
+                        plane_fit_tolerance: 0.01,
+                        variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+                    },
+                    descriptor: Descriptor::DetectorDefined,
+                },
+            },
+        );
+        let config = ObjectSpaceConfig { components };
+        config.validate().unwrap();
+
+        assert!(config.component("cam_left").is_some());
+        assert!(config.component("does_not_exist").is_none());
+        assert_eq!(config.components().count(), 2);
+        assert_eq!(
+            config.components_of_kind(ComponentKind::Camera).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn valid_detector_descriptor_pairing_validates() {
+        let dd = DetectorDescriptor {
+            detector: checkerboard(7, 5, 0.1),
+            descriptor: Descriptor::DetectorDefined,
+        };
+        dd.validate("camera").unwrap();
+    }
+
+    #[test]
+    fn board_dimension_too_small_is_rejected() {
+        let dd = DetectorDescriptor {
+            detector: checkerboard(1, 5, 0.1),
+            descriptor: Descriptor::DetectorDefined,
+        };
+        assert_eq!(
+            dd.validate("camera").unwrap_err(),
+            ConfigError::BoardDimensionTooSmall {
+                component: "camera".to_string(),
+                field: "width",
+                value: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn aruco_grid_board_dimension_too_small_reports_markers_field() {
+        let dd = DetectorDescriptor {
+            detector: Detector::ArucoGrid {
+                dictionary: ArucoDictionary::Apriltag36h11,
+                markers_x: 1,
+                markers_y: 5,
+                marker_length: 0.05,
+                marker_separation: 0.01,
+                variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+            },
+            descriptor: Descriptor::DetectorDefined,
+        };
+        assert_eq!(
+            dd.validate("lidar_top").unwrap_err(),
+            ConfigError::BoardDimensionTooSmall {
+                component: "lidar_top".to_string(),
+                field: "markers_x",
+                value: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn non_positive_edge_length_is_rejected() {
+        let dd = DetectorDescriptor {
+            detector: checkerboard(7, 5, 0.0),
+            descriptor: Descriptor::DetectorDefined,
+        };
+        dd.validate("camera").unwrap_err();
+    }
+
+    #[test]
+    fn charuco_marker_length_must_be_less_than_edge_length() {
+        let dd = DetectorDescriptor {
+            detector: Detector::Charuco {
+                width: 7,
+                height: 5,
+                edge_length: 0.1,
+                marker_length: 0.2,
+                variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+            },
+            descriptor: Descriptor::DetectorDefined,
+        };
+        dd.validate("camera").unwrap_err();
+    }
+
+    #[test]
+    fn descriptor_not_allowed_for_detector_is_rejected() {
+        let dd = DetectorDescriptor {
+            detector: checkerboard(7, 5, 0.1),
+            descriptor: Descriptor::LandmarkMap { landmarks: vec![] },
+        };
+        assert_eq!(
+            dd.validate("camera").unwrap_err(),
+            ConfigError::DescriptorNotAllowedForDetector {
+                component: "camera".to_string(),
+                detector: "checkerboard",
+                descriptor: "landmark_map",
+            }
+        );
+    }
+
+    #[test]
+    fn aruco_grid_allows_landmark_map() {
+        let dd = DetectorDescriptor {
+            detector: Detector::ArucoGrid {
+                dictionary: ArucoDictionary::Apriltag36h11,
+                markers_x: 5,
+                markers_y: 5,
+                marker_length: 0.05,
+                marker_separation: 0.01,
+                variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+            },
+            descriptor: Descriptor::LandmarkMap {
+                landmarks: vec![Landmark {
+                    id: 1,
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    covariance: None,
+                }],
+            },
+        };
+        dd.validate("camera").unwrap();
+    }
+
+    #[test]
+    fn duplicate_landmark_ids_are_rejected() {
+        let landmark = |id| Landmark {
+            id,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            covariance: None,
+        };
+        let dd = DetectorDescriptor {
+            detector: Detector::ArucoGrid {
+                dictionary: ArucoDictionary::Apriltag36h11,
+                markers_x: 5,
+                markers_y: 5,
+                marker_length: 0.05,
+                marker_separation: 0.01,
+                variances: Uncertainty::try_from(vec![1.0, 1.0, 1.0]).unwrap(),
+            },
+            descriptor: Descriptor::LandmarkMap {
+                landmarks: vec![landmark(1), landmark(1)],
+            },
+        };
+        assert_eq!(
+            dd.validate("camera").unwrap_err(),
+            ConfigError::DuplicateLandmarkId {
+                component: "camera".to_string(),
+                landmark_id: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn uncertainty_from_three_values_is_diagonal() {
+        let uncertainty = Uncertainty::try_from(vec![1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(
+            uncertainty.matrix(),
+            [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]
+        );
+    }
+
+    #[test]
+    fn uncertainty_from_six_values_is_symmetric() {
+        let uncertainty = Uncertainty::try_from(vec![1.0, 0.1, 0.2, 2.0, 0.3, 3.0]).unwrap();
+        assert_eq!(
+            uncertainty.matrix(),
+            [[1.0, 0.1, 0.2], [0.1, 2.0, 0.3], [0.2, 0.3, 3.0]]
+        );
+    }
+
+    #[test]
+    fn uncertainty_from_nine_values_must_be_symmetric() {
+        Uncertainty::try_from(vec![1.0, 0.1, 0.2, 0.1, 2.0, 0.3, 0.2, 0.3, 3.0]).unwrap();
+        Uncertainty::try_from(vec![1.0, 0.1, 0.2, 9.9, 2.0, 0.3, 0.2, 0.3, 3.0]).unwrap_err();
+    }
+
+    #[test]
+    fn uncertainty_must_be_positive_semidefinite() {
+        Uncertainty::try_from(vec![-1.0, 0.0, 1.0]).unwrap_err();
+    }
+
+    #[test]
+    fn uncertainty_rejects_negative_eigenvalue_hidden_behind_a_leading_zero() {
+        // diag(0, -1, 0): the leading 1x1 and 2x2 principal minors are both 0, so a test that
+        // only looks at leading minors would wrongly accept this.
+        Uncertainty::try_from(vec![0.0, 0.0, 0.0, -1.0, 0.0, 0.0]).unwrap_err();
+    }
+
+    #[test]
+    fn uncertainty_rejects_wrong_length() {
+        Uncertainty::try_from(vec![1.0, 2.0]).unwrap_err();
+    }
+
     #[test]
     fn valid_checkerboard_is_ok() {
         read_object_space_config("fixtures/checkerboard_detector.toml").unwrap();
@@ -120,4 +895,14 @@ mod tests {
     fn file_that_does_not_exist_is_err() {
         read_object_space_config("fixtures/i-do-not-exist.png").unwrap_err();
     }
+
+    #[test]
+    fn config_round_trips_through_toml() {
+        let config = read_object_space_config("fixtures/multi_component.toml").unwrap();
+
+        let toml_string = to_toml_string(&config).unwrap();
+        let round_tripped: ObjectSpaceConfig = toml::from_str(&toml_string).unwrap();
+
+        assert_eq!(config, round_tripped);
+    }
 }